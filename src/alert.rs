@@ -0,0 +1,88 @@
+use std::fmt::Display;
+use std::io;
+
+use console::{Key, Term};
+
+use crate::interaction::{Event, PromptInteraction, State};
+use crate::theme::ClackTheme;
+
+/// The severity styling applied to an [`Alert`] message.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AlertKind {
+    /// Neutral styling, matching [`crate::log::info`].
+    Info,
+    /// Warning styling, matching [`crate::log::warning`].
+    Warning,
+    /// Error styling, matching [`crate::log::error`].
+    Error,
+}
+
+/// A blocking acknowledgement prompt: "press Enter to continue".
+///
+/// Unlike [`crate::confirm`], the only key it responds to is `Enter`,
+/// which makes it a good fit for surfacing a warning or error mid-sequence
+/// without offering a meaningless yes/no choice. Like every other prompt,
+/// `Esc` still cancels the whole sequence, so this doesn't force
+/// acknowledgment — it just removes the choice of *answer*.
+///
+/// See [`crate::alert`] for a usage example.
+pub struct Alert {
+    prompt: String,
+    kind: AlertKind,
+}
+
+impl Alert {
+    /// Creates a new alert with the given message and [`AlertKind::Info`]
+    /// styling.
+    pub fn new<S: Display>(prompt: S) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            kind: AlertKind::Info,
+        }
+    }
+
+    /// Renders the message with warning styling.
+    pub fn warning(mut self) -> Self {
+        self.kind = AlertKind::Warning;
+        self
+    }
+
+    /// Renders the message with error styling.
+    pub fn error(mut self) -> Self {
+        self.kind = AlertKind::Error;
+        self
+    }
+
+    /// Blocks until the user presses `Enter`.
+    pub fn interact(&mut self) -> io::Result<()> {
+        <Self as PromptInteraction<()>>::interact(self)
+    }
+
+    /// Runs the prompt on an arbitrary terminal, e.g. an application
+    /// multiplexing several real terminals.
+    pub fn interact_on(&mut self, term: &Term) -> io::Result<()> {
+        <Self as PromptInteraction<()>>::interact_on(self, term)
+    }
+
+    /// Runs the prompt against a fixed sequence of keys, rendering to
+    /// `term`. Useful for deterministic tests.
+    pub fn interact_with_keys<I>(&mut self, keys: I, term: &Term) -> io::Result<()>
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        <Self as PromptInteraction<()>>::interact_with_keys(self, keys, term)
+    }
+}
+
+impl PromptInteraction<()> for Alert {
+    fn notify(&mut self, event: &Event) -> State<()> {
+        match event {
+            Event::Key(Key::Enter) => State::Submit(()),
+            _ => State::Active,
+        }
+    }
+
+    fn render(&mut self, state: &State<()>) -> String {
+        ClackTheme.render_alert(state, &self.prompt, self.kind)
+    }
+}
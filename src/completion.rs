@@ -0,0 +1,17 @@
+/// Supplies tab-completion suggestions for a [`crate::Text`]/[`crate::Input`]
+/// prompt.
+///
+/// Implement this to offer command/path completion inside an input prompt,
+/// then attach it with [`crate::Text::completion`].
+pub trait Completion {
+    /// Returns the full completed value for the current `input`, if any.
+    ///
+    /// Returning `None` leaves the input untouched.
+    fn complete(&self, input: &str) -> Option<String>;
+}
+
+impl<F: Fn(&str) -> Option<String>> Completion for F {
+    fn complete(&self, input: &str) -> Option<String> {
+        self(input)
+    }
+}
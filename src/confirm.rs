@@ -0,0 +1,75 @@
+use std::fmt::Display;
+use std::io;
+
+use console::{Key, Term};
+
+use crate::interaction::{Event, PromptInteraction, State};
+use crate::theme::ClackTheme;
+
+/// A yes/no prompt. See [`crate::confirm`] for a usage example.
+pub struct Confirm {
+    prompt: String,
+    initial_value: bool,
+    active: String,
+    inactive: String,
+}
+
+impl Confirm {
+    /// Creates a new confirm prompt with the given message.
+    pub fn new<S: Display>(prompt: S) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            initial_value: true,
+            active: "Yes".to_string(),
+            inactive: "No".to_string(),
+        }
+    }
+
+    /// Sets which option (`true`/`false`) is initially highlighted.
+    pub fn initial_value(mut self, initial_value: bool) -> Self {
+        self.initial_value = initial_value;
+        self
+    }
+
+    /// Runs the prompt and returns the selected boolean.
+    pub fn interact(&mut self) -> io::Result<bool> {
+        <Self as PromptInteraction<bool>>::interact(self)
+    }
+
+    /// Runs the prompt on an arbitrary terminal, e.g. an application
+    /// multiplexing several real terminals.
+    pub fn interact_on(&mut self, term: &Term) -> io::Result<bool> {
+        <Self as PromptInteraction<bool>>::interact_on(self, term)
+    }
+
+    /// Runs the prompt against a fixed sequence of keys, rendering to
+    /// `term`. Useful for deterministic tests.
+    pub fn interact_with_keys<I>(&mut self, keys: I, term: &Term) -> io::Result<bool>
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        <Self as PromptInteraction<bool>>::interact_with_keys(self, keys, term)
+    }
+}
+
+impl PromptInteraction<bool> for Confirm {
+    fn notify(&mut self, event: &Event) -> State<bool> {
+        match event {
+            Event::Key(key) => match key {
+                Key::Char('y') | Key::Char('Y') => return State::Submit(true),
+                Key::Char('n') | Key::Char('N') => return State::Submit(false),
+                Key::ArrowLeft | Key::ArrowRight | Key::Tab => {
+                    self.initial_value = !self.initial_value;
+                }
+                Key::Enter => return State::Submit(self.initial_value),
+                _ => {}
+            },
+        }
+
+        State::Active
+    }
+
+    fn render(&mut self, state: &State<bool>) -> String {
+        ClackTheme.render_confirm(state, &self.prompt, &self.active, &self.inactive, self.initial_value)
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt::{self, Display};
+
+/// A simple string buffer with an editable cursor position, shared by
+/// prompts that accept free-form text input.
+#[derive(Debug, Default, Clone)]
+pub struct StringCursor {
+    chars: Vec<char>,
+    cursor: usize,
+}
+
+impl StringCursor {
+    /// Appends `text` at the current cursor position, advancing the cursor
+    /// past the inserted characters.
+    pub fn extend(&mut self, text: &str) {
+        for chr in text.chars() {
+            self.insert(chr);
+        }
+    }
+
+    /// Inserts a single character at the cursor and advances it.
+    pub fn insert(&mut self, chr: char) {
+        self.chars.insert(self.cursor, chr);
+        self.cursor += 1;
+    }
+
+    /// Deletes the character to the left of the cursor, if any.
+    pub fn delete_left(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.chars.remove(self.cursor);
+        }
+    }
+
+    /// Deletes the character to the right of the cursor, if any.
+    pub fn delete_right(&mut self) {
+        if self.cursor < self.chars.len() {
+            self.chars.remove(self.cursor);
+        }
+    }
+
+    /// Moves the cursor one character to the left.
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    /// Moves the cursor one character to the right.
+    pub fn move_right(&mut self) {
+        if self.cursor < self.chars.len() {
+            self.cursor += 1;
+        }
+    }
+
+    /// Moves the cursor to the end of the buffer.
+    pub fn move_end(&mut self) {
+        self.cursor = self.chars.len();
+    }
+
+    /// Returns `true` if the cursor sits past the last character, i.e. at
+    /// the end of the buffer.
+    pub fn is_cursor_at_end(&self) -> bool {
+        self.cursor == self.chars.len()
+    }
+
+    /// Replaces the whole buffer with `text`, placing the cursor at the end.
+    pub fn replace(&mut self, text: &str) {
+        self.chars = text.chars().collect();
+        self.move_end();
+    }
+
+    /// Clears the buffer and resets the cursor, zeroing the previous
+    /// contents first so sensitive input (e.g. a password) doesn't linger
+    /// in the backing allocation — `Vec::clear` alone only drops the
+    /// length, it doesn't overwrite the memory.
+    pub fn clear(&mut self) {
+        self.wipe();
+        self.chars.clear();
+        self.cursor = 0;
+    }
+
+    /// Returns `true` if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    fn wipe(&mut self) {
+        for chr in self.chars.iter_mut() {
+            *chr = '\0';
+        }
+    }
+}
+
+impl Drop for StringCursor {
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+impl Display for StringCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chr in &self.chars {
+            write!(f, "{chr}")?;
+        }
+        Ok(())
+    }
+}
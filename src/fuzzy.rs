@@ -0,0 +1,85 @@
+use strsim::jaro_winkler;
+
+/// Subsequence fuzzy match used by [`crate::FuzzySelect`].
+///
+/// Returns `None` if `query`'s characters don't all appear, in order,
+/// somewhere in `candidate` (case-insensitively). Otherwise returns a score
+/// (higher is a better match) plus the matched characters' positions as
+/// `chars()` ordinals (not byte offsets) into `candidate`, for highlighting.
+pub(crate) fn fuzzy_match(query: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let query_lower: Vec<char> = query.to_lowercase().chars().collect();
+    // Lowercase char-by-char (rather than `candidate.to_lowercase()`) so this
+    // stays index-aligned with `candidate`: some characters (e.g. Turkish
+    // `İ`) lowercase to multiple codepoints, which would otherwise desync
+    // the two and panic on the indexing below.
+    let candidate_lower: Vec<char> = candidate
+        .chars()
+        .map(|c| c.to_lowercase().next().unwrap())
+        .collect();
+
+    let mut matched_at = Vec::with_capacity(query_lower.len());
+    let mut score = 0i64;
+    let mut search_from = 0;
+    let mut prev_match: Option<usize> = None;
+
+    for chr in &query_lower {
+        let offset = candidate_lower[search_from..].iter().position(|c| c == chr)?;
+        let pos = search_from + offset;
+
+        score += 1;
+        if prev_match == Some(pos.wrapping_sub(1)) {
+            score += 3; // consecutive run
+        }
+        if pos == 0 || candidate_lower[pos - 1] == ' ' {
+            score += 2; // word boundary
+        }
+
+        matched_at.push(pos);
+        prev_match = Some(pos);
+        search_from = pos + 1;
+    }
+
+    let similarity = jaro_winkler(&query.to_lowercase(), &candidate.to_lowercase());
+    score += (similarity * 10.0) as i64;
+
+    Some((score, matched_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_case_insensitive_subsequence() {
+        let (_, positions) = fuzzy_match("tsc", "TypeScript").unwrap();
+        assert_eq!(positions, vec![0, 4, 5]);
+    }
+
+    #[test]
+    fn rejects_out_of_order_or_missing_characters() {
+        assert!(fuzzy_match("xyz", "TypeScript").is_none());
+        assert!(fuzzy_match("ts", "script").is_none());
+    }
+
+    #[test]
+    fn scores_consecutive_runs_and_word_boundaries_higher() {
+        let (consecutive, _) = fuzzy_match("ty", "TypeScript").unwrap();
+        let (scattered, _) = fuzzy_match("tt", "TypeScript").unwrap();
+        assert!(consecutive > scattered);
+
+        let (boundary, _) = fuzzy_match("s", "Type Script").unwrap();
+        let (mid_word, _) = fuzzy_match("s", "Types").unwrap();
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn does_not_panic_on_expanding_lowercase_mappings() {
+        // U+0130 (İ) lowercases to the two-codepoint "i̇", which used to
+        // desync the candidate's char vector from its lowered one.
+        assert!(fuzzy_match("y", "\u{0130}\u{0130}Y").is_some());
+    }
+}
@@ -0,0 +1,113 @@
+use std::io;
+
+use console::{Key, Term};
+
+use crate::cursor::StringCursor;
+use crate::fuzzy::fuzzy_match;
+use crate::interaction::{Event, PromptInteraction, State};
+use crate::select::SelectItem;
+use crate::theme::ClackTheme;
+
+/// A [`crate::Select`] variant that narrows the item list as the user
+/// types, using fuzzy subsequence matching.
+///
+/// See [`crate::Select::fuzzy`] for a usage example.
+pub struct FuzzySelect<T> {
+    prompt: String,
+    items: Vec<SelectItem<T>>,
+    query: StringCursor,
+    cursor: usize,
+}
+
+impl<T: Clone + Eq> FuzzySelect<T> {
+    pub(crate) fn from_items(prompt: String, items: Vec<SelectItem<T>>, cursor: usize) -> Self {
+        Self {
+            prompt,
+            items,
+            query: StringCursor::default(),
+            cursor,
+        }
+    }
+
+    /// Runs the prompt and returns the selected item's value.
+    pub fn interact(&mut self) -> io::Result<T> {
+        <Self as PromptInteraction<T>>::interact(self)
+    }
+
+    /// Runs the prompt on an arbitrary terminal, e.g. an application
+    /// multiplexing several real terminals.
+    pub fn interact_on(&mut self, term: &Term) -> io::Result<T> {
+        <Self as PromptInteraction<T>>::interact_on(self, term)
+    }
+
+    /// Runs the prompt against a fixed sequence of keys, rendering to
+    /// `term`. Useful for deterministic tests.
+    pub fn interact_with_keys<I>(&mut self, keys: I, term: &Term) -> io::Result<T>
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        <Self as PromptInteraction<T>>::interact_with_keys(self, keys, term)
+    }
+
+    /// Returns the items matching the current query, sorted by descending
+    /// score (stable for ties, preserving the original item order).
+    fn matches(&self) -> Vec<(usize, Vec<usize>)> {
+        let query = self.query.to_string();
+        let mut matches: Vec<(usize, i64, Vec<usize>)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                fuzzy_match(&query, &item.label).map(|(score, positions)| (index, score, positions))
+            })
+            .collect();
+
+        matches.sort_by_key(|&(_, score, _)| std::cmp::Reverse(score));
+        matches
+            .into_iter()
+            .map(|(index, _, positions)| (index, positions))
+            .collect()
+    }
+}
+
+impl<T: Clone + Eq> PromptInteraction<T> for FuzzySelect<T> {
+    fn notify(&mut self, event: &Event) -> State<T> {
+        match event {
+            Event::Key(key) => match key {
+                Key::Char(chr) if !chr.is_ascii_control() => {
+                    self.query.insert(*chr);
+                    self.cursor = 0;
+                }
+                Key::Backspace => {
+                    self.query.delete_left();
+                    self.cursor = 0;
+                }
+                Key::ArrowUp if self.cursor > 0 => self.cursor -= 1,
+                Key::ArrowDown if self.cursor + 1 < self.matches().len() => {
+                    self.cursor += 1;
+                }
+                Key::Enter => {
+                    if let Some((index, _)) = self.matches().get(self.cursor) {
+                        return State::Submit(self.items[*index].value.clone());
+                    }
+                }
+                _ => {}
+            },
+        }
+
+        State::Active
+    }
+
+    fn render(&mut self, state: &State<T>) -> String {
+        let submitted = matches!(state, State::Submit(_));
+        let matches = self.matches();
+        let rows: Vec<(&str, &str, &[usize])> = matches
+            .iter()
+            .map(|(index, positions)| {
+                let item = &self.items[*index];
+                (item.label.as_str(), item.hint.as_str(), positions.as_slice())
+            })
+            .collect();
+        ClackTheme.render_fuzzy_select(submitted, &self.prompt, &self.query, &rows, self.cursor)
+    }
+}
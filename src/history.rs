@@ -0,0 +1,14 @@
+/// Supplies previously submitted values to a [`crate::Text`]/[`crate::Input`]
+/// prompt, navigable with the `Up`/`Down` arrow keys.
+///
+/// Implement this to back a REPL-style loop where users scroll previously
+/// entered values instead of retyping them, then attach it with
+/// [`crate::Text::history`].
+pub trait History {
+    /// Returns the entry `pos` steps back from the newest one, if any,
+    /// where `pos == 0` is the most recently written value.
+    fn read(&self, pos: usize) -> Option<String>;
+
+    /// Records a newly submitted value.
+    fn write(&mut self, val: &str);
+}
@@ -0,0 +1,104 @@
+use std::fmt::Display;
+use std::io;
+use std::str::FromStr;
+
+use console::{Key, Term};
+
+use crate::completion::Completion;
+use crate::history::History;
+use crate::text::Text;
+use crate::validate::Validate;
+
+/// A single-line prompt that parses the submitted value into `T`.
+///
+/// See [`crate::input`] for a usage example.
+pub struct Input<'a> {
+    text: Text<'a>,
+}
+
+impl<'a> Input<'a> {
+    /// Creates a new input prompt with the given message.
+    pub fn new<S: Display>(prompt: S) -> Self {
+        Self {
+            text: Text::new(prompt),
+        }
+    }
+
+    /// Sets a placeholder shown (dimmed) while the input is empty.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.text = self.text.placeholder(placeholder);
+        self
+    }
+
+    /// Sets a validator run against the raw string before parsing.
+    pub fn validate<V>(mut self, validator: V) -> Self
+    where
+        V: Validate<str> + 'static,
+    {
+        self.text = self.text.validate(validator);
+        self
+    }
+
+    /// Attaches a [`Completion`] offering tab-completion suggestions as the
+    /// user types.
+    pub fn completion<C: Completion + 'static>(mut self, completion: C) -> Self {
+        self.text = self.text.completion(completion);
+        self
+    }
+
+    /// Attaches a [`History`], letting the user scroll previously submitted
+    /// values with the `Up`/`Down` arrow keys.
+    pub fn history(mut self, history: &'a mut dyn History) -> Self {
+        self.text = self.text.history(history);
+        self
+    }
+
+    /// Pre-populates the editable buffer with `value`, cursor at the end,
+    /// so the user edits an existing value rather than starting empty.
+    pub fn initial_value(mut self, value: &str) -> Self {
+        self.text = self.text.initial_value(value);
+        self
+    }
+
+    /// Substitutes `value` for the raw string when the user submits an
+    /// empty buffer, before parsing and validation.
+    pub fn default_value(mut self, value: &str) -> Self {
+        self.text = self.text.default_value(value);
+        self
+    }
+
+    /// Runs the prompt and parses the submitted value into `T`.
+    pub fn interact<T>(&mut self) -> io::Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.interact_on(&Term::stderr())
+    }
+
+    /// Runs the prompt on an arbitrary terminal, e.g. an application
+    /// multiplexing several real terminals.
+    pub fn interact_on<T>(&mut self, term: &Term) -> io::Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let raw = self.text.interact_on(term)?;
+        raw.parse::<T>()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+    }
+
+    /// Runs the prompt against a fixed sequence of keys, rendering to
+    /// `term`, and parses the submitted value into `T`. Useful for
+    /// deterministic tests.
+    pub fn interact_with_keys<T, I>(&mut self, keys: I, term: &Term) -> io::Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+        I: IntoIterator<Item = Key>,
+    {
+        let raw = self.text.interact_with_keys(keys, term)?;
+        raw.parse::<T>()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+    }
+}
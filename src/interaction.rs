@@ -0,0 +1,98 @@
+use std::io;
+
+use console::{Key, Term};
+
+/// An input event delivered to a prompt's [`PromptInteraction::notify`].
+pub enum Event {
+    /// A key was pressed.
+    Key(Key),
+}
+
+/// The lifecycle state of a prompt.
+pub enum State<T> {
+    /// The prompt is awaiting input.
+    Active,
+    /// The prompt rejected the current input with a message.
+    Error(String),
+    /// The prompt was completed with a final value.
+    Submit(T),
+    /// The prompt was cancelled by the user (e.g. `Esc`).
+    Cancel,
+}
+
+/// Implemented by every prompt to drive its render/input loop.
+///
+/// Prompts only need to implement [`notify`](Self::notify) and
+/// [`render`](Self::render); the interaction loop itself is shared.
+pub trait PromptInteraction<T> {
+    /// Handles an incoming event, returning the resulting state.
+    fn notify(&mut self, event: &Event) -> State<T>;
+
+    /// Renders the prompt for the given state.
+    fn render(&mut self, state: &State<T>) -> String;
+
+    /// Runs the prompt on the standard error terminal until it is submitted
+    /// or cancelled.
+    fn interact(&mut self) -> io::Result<T> {
+        self.interact_on(&Term::stderr())
+    }
+
+    /// Runs the prompt on an arbitrary terminal until it is submitted or
+    /// cancelled.
+    ///
+    /// This redirects rendering, but `console::Term`'s key-reading always
+    /// reads the process's real stdin/tty, no matter which `Term` it's
+    /// called on. So this is useful for multiplexing several real
+    /// terminals, but *not* for deterministic tests; use
+    /// [`interact_with_keys`](Self::interact_with_keys) for those instead.
+    fn interact_on(&mut self, term: &Term) -> io::Result<T> {
+        self.drive(term, || term.read_key())
+    }
+
+    /// Runs the prompt against a fixed sequence of keys instead of reading
+    /// real input, rendering to `term`.
+    ///
+    /// This is the deterministic counterpart to [`interact_on`](Self::interact_on):
+    /// since `console::Term` has no way to fake key input, tests should
+    /// drive the prompt directly with the keys they want to simulate.
+    fn interact_with_keys<I>(&mut self, keys: I, term: &Term) -> io::Result<T>
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        let mut keys = keys.into_iter();
+        self.drive(term, || {
+            keys.next()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "no more keys"))
+        })
+    }
+
+    /// Shared render/input loop behind [`interact_on`](Self::interact_on)
+    /// and [`interact_with_keys`](Self::interact_with_keys); `next_key`
+    /// supplies each key to act on.
+    fn drive(&mut self, term: &Term, mut next_key: impl FnMut() -> io::Result<Key>) -> io::Result<T> {
+        let mut state = State::Active;
+        let mut last_lines = 0u16;
+
+        loop {
+            let rendered = self.render(&state);
+            if last_lines > 0 {
+                term.clear_last_lines(last_lines as usize)?;
+            }
+            term.write_str(&rendered)?;
+            last_lines = rendered.matches('\n').count() as u16;
+
+            match state {
+                State::Submit(value) => return Ok(value),
+                State::Cancel => {
+                    return Err(io::Error::new(io::ErrorKind::Interrupted, "prompt cancelled"))
+                }
+                _ => {}
+            }
+
+            state = match next_key()? {
+                Key::Escape => State::Cancel,
+                key => self.notify(&Event::Key(key)),
+            };
+        }
+    }
+}
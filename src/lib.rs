@@ -8,7 +8,8 @@
 //! ✅ Simple API.<br>
 //! 🧱 Comes with [`input`](fn@input), [`password`](fn@password),
 //!    [`confirm`](fn@confirm), [`select`](fn@select),
-//!    [`multiselect`](fn@multiselect), and [`spinner`](fn@spinner) prompts.<br>
+//!    [`multiselect`](fn@multiselect), [`spinner`](fn@spinner), and
+//!    [`alert`](fn@alert) prompts.<br>
 //! 🧱 Styled non-interactive messages with [`log`] submodule.<br>
 //! 🎨 [`Theme`] support.<br>
 //!
@@ -48,13 +49,13 @@
 //! The input prompt accepts a single line of text trying to parse it into
 //! a target type.
 //!
-//! ```
+//! ```no_run
 //! use cliclack::input;
 //!
 //! # fn test() -> std::io::Result<()> {
 //! let number: String = input("What is the meaning of life?")
 //!     .placeholder("Not sure")
-//!     .validate(|input: &String| {
+//!     .validate(|input: &str| {
 //!         if input.is_empty() {
 //!             Err("Value is required!")
 //!         } else {
@@ -67,12 +68,80 @@
 //! # test().ok(); // Ignoring I/O runtime errors.
 //! ```
 //!
+//! [`Input::initial_value`] pre-populates the editable buffer, and
+//! [`Input::default_value`] substitutes a fallback when the user submits
+//! an empty value:
+//!
+//! ```no_run
+//! # fn test() -> std::io::Result<()> {
+//! use cliclack::input;
+//!
+//! let name: String = input("Project name")
+//!     .initial_value("my-app")
+//!     .default_value("my-app")
+//!     .interact()?;
+//! # Ok(())
+//! # }
+//! # test().ok(); // Ignoring I/O runtime errors.
+//! ```
+//!
+//! ## Input completion
+//!
+//! [`Input`]/[`Text`] prompts can offer tab-completion by attaching a
+//! [`Completion`].
+//!
+//! ```no_run
+//! # fn test() -> std::io::Result<()> {
+//! use cliclack::input;
+//!
+//! let path: String = input("Path to config")
+//!     .completion(|input: &str| {
+//!         if "config.toml".starts_with(input) {
+//!             Some("config.toml".to_string())
+//!         } else {
+//!             None
+//!         }
+//!     })
+//!     .interact()?;
+//! # Ok(())
+//! # }
+//! # test().ok(); // Ignoring I/O runtime errors.
+//! ```
+//!
+//! ## Input history
+//!
+//! Attach a [`History`] to let the user scroll previously submitted values
+//! with the `Up`/`Down` arrow keys, e.g. in a REPL-style loop.
+//!
+//! ```no_run
+//! # fn test() -> std::io::Result<()> {
+//! use cliclack::{input, History};
+//!
+//! struct VecHistory(Vec<String>);
+//!
+//! impl History for VecHistory {
+//!     fn read(&self, pos: usize) -> Option<String> {
+//!         self.0.iter().rev().nth(pos).cloned()
+//!     }
+//!
+//!     fn write(&mut self, val: &str) {
+//!         self.0.push(val.to_string());
+//!     }
+//! }
+//!
+//! let mut history = VecHistory(Vec::new());
+//! let command: String = input("Command").history(&mut history).interact()?;
+//! # Ok(())
+//! # }
+//! # test().ok(); // Ignoring I/O runtime errors.
+//! ```
+//!
 //! ## Password
 //!
 //! The password prompt is similar to the input prompt, but it doesn't echo the
 //! actual characters.
 //!
-//! ```
+//! ```no_run
 //! # fn test() -> std::io::Result<()> {
 //! use cliclack::password;
 //!
@@ -84,13 +153,28 @@
 //! # test().ok(); // Ignoring I/O runtime errors.
 //! ```
 //!
+//! Use [`Password::with_confirmation`] for the common "New password /
+//! Confirm password" flow:
+//!
+//! ```no_run
+//! # fn test() -> std::io::Result<()> {
+//! use cliclack::password;
+//!
+//! let password = password("New password")
+//!     .with_confirmation("Confirm password", "Passwords do not match.")
+//!     .interact()?;
+//! # Ok(())
+//! # }
+//! # test().ok(); // Ignoring I/O runtime errors.
+//! ```
+//!
 //! ## Confirm
 //!
 //! The confirm prompt asks for a yes/no answer. It returns a boolean (`true`/`false`).
 //!
 //! '`Y`' and '`N`' keys are accepted as an immediate answer.
 //!
-//! ```
+//! ```no_run
 //! # fn test() -> std::io::Result<()> {
 //! use cliclack::confirm;
 //!
@@ -104,7 +188,24 @@
 //!
 //! The select prompt asks to choose one of the options from the list.
 //!
+//! ```no_run
+//! # fn test() -> std::io::Result<()> {
+//! use cliclack::select;
+//!
+//! let selected = select("Pick a project type")
+//!     .item("ts", "TypeScript", "")
+//!     .item("js", "JavaScript", "")
+//!     .item("coffee", "CoffeeScript", "oh no")
+//!     .interact()?;
+//! # Ok(())
+//! # }
+//! # test().ok(); // Ignoring I/O runtime errors.
 //! ```
+//!
+//! Call [`Select::fuzzy`] to let the user type to narrow a long item list
+//! instead of scrolling through all of it:
+//!
+//! ```no_run
 //! # fn test() -> std::io::Result<()> {
 //! use cliclack::select;
 //!
@@ -112,6 +213,7 @@
 //!     .item("ts", "TypeScript", "")
 //!     .item("js", "JavaScript", "")
 //!     .item("coffee", "CoffeeScript", "oh no")
+//!     .fuzzy()
 //!     .interact()?;
 //! # Ok(())
 //! # }
@@ -123,7 +225,7 @@
 //! The multi-select prompt asks to choose one or more options from the list.
 //! The result is a vector of selected items.
 //!
-//! ```
+//! ```no_run
 //! # fn test() -> std::io::Result<()> {
 //! use cliclack::multiselect;
 //!
@@ -152,6 +254,22 @@
 //! # test().ok(); // Ignoring I/O runtime errors.
 //! ```
 //!
+//! ## Alert
+//!
+//! The alert prompt offers no response but `Enter`, useful for a
+//! "something went wrong" message that needs acknowledging before moving
+//! on (`Esc` still cancels the sequence, as with every other prompt).
+//!
+//! ```no_run
+//! # fn test() -> std::io::Result<()> {
+//! use cliclack::alert;
+//!
+//! alert("This action cannot be undone.").warning().interact()?;
+//! # Ok(())
+//! # }
+//! # test().ok(); // Ignoring I/O runtime errors.
+//! ```
+//!
 //! ## Logging
 //!
 //! Plain text output without any interaction.
@@ -168,6 +286,30 @@
 //! # test().ok(); // Ignoring I/O runtime errors.
 //! ```
 //!
+//! ## Custom terminals
+//!
+//! Every prompt normally drives `Term::stderr()`, but `interact_on` lets it
+//! run against any [`console::Term`] instead, e.g. for apps that multiplex
+//! several real terminals.
+//!
+//! `console::Term`'s key-reading always reads the process's real stdin, no
+//! matter which `Term` it's given, so `interact_on` can't be driven
+//! deterministically. For tests, use `interact_with_keys` to supply the
+//! exact keys to act on instead:
+//!
+//! ```
+//! # fn test() -> std::io::Result<()> {
+//! use cliclack::confirm;
+//! use console::{Key, Term};
+//!
+//! let should_continue = confirm("Do you want to continue?")
+//!     .interact_with_keys([Key::Char('y')], &Term::buffered_stderr())?;
+//! assert!(should_continue);
+//! # Ok(())
+//! # }
+//! # test().unwrap();
+//! ```
+//!
 //! ## Theme
 //!
 //! Custom UI is supported via the [`Theme`] trait.
@@ -199,13 +341,20 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs, unused_qualifications)]
 
+mod alert;
+mod completion;
 mod confirm;
+mod cursor;
+mod fuzzy;
+mod fuzzy_select;
+mod history;
 mod input;
+mod interaction;
 mod multiselect;
 mod password;
-mod prompt;
 mod select;
 mod spinner;
+mod text;
 mod theme;
 mod validate;
 
@@ -218,12 +367,17 @@ use theme::THEME;
 // 🎨 Re-export of the theme API.
 pub use theme::{reset_theme, set_theme, Theme, ThemeState};
 
+pub use alert::{Alert, AlertKind};
+pub use completion::Completion;
 pub use confirm::Confirm;
+pub use fuzzy_select::FuzzySelect;
+pub use history::History;
 pub use input::Input;
 pub use multiselect::MultiSelect;
 pub use password::Password;
 pub use select::Select;
 pub use spinner::Spinner;
+pub use text::Text;
 pub use validate::Validate;
 
 fn term_write(line: impl Display) -> io::Result<()> {
@@ -256,6 +410,7 @@ pub fn outro_cancel(message: impl Display) -> io::Result<()> {
     )
 }
 
+/// Prints a footer note of the prompt sequence.
 pub fn outro_note(prompt: impl Display, message: impl Display) -> io::Result<()> {
     term_write(
         THEME
@@ -269,7 +424,7 @@ pub fn outro_note(prompt: impl Display, message: impl Display) -> io::Result<()>
 /// Constructs a new [`Input`] prompt.
 ///
 /// See [`Input`] for chainable methods.
-pub fn input(prompt: impl Display) -> Input {
+pub fn input<'a>(prompt: impl Display) -> Input<'a> {
     Input::new(prompt)
 }
 
@@ -308,6 +463,14 @@ pub fn spinner() -> Spinner {
     Spinner::default()
 }
 
+/// Constructs a new [`Alert`], a blocking "press Enter to continue"
+/// acknowledgement.
+///
+/// See [`Alert`] for chainable methods.
+pub fn alert(prompt: impl Display) -> Alert {
+    Alert::new(prompt)
+}
+
 /// Prints a note message.
 pub fn note(prompt: impl Display, message: impl Display) -> io::Result<()> {
     term_write(
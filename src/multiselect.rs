@@ -0,0 +1,101 @@
+use std::fmt::Display;
+use std::io;
+
+use console::{Key, Term};
+
+use crate::interaction::{Event, PromptInteraction, State};
+use crate::select::SelectItem;
+use crate::theme::ClackTheme;
+
+/// A multiple-choice prompt over a list of items.
+///
+/// See [`crate::multiselect`] for a usage example.
+pub struct MultiSelect<T> {
+    prompt: String,
+    items: Vec<SelectItem<T>>,
+    cursor: usize,
+    selected: Vec<bool>,
+}
+
+impl<T: Clone + Eq> MultiSelect<T> {
+    /// Creates a new multi-select prompt with the given message.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            items: Vec::new(),
+            cursor: 0,
+            selected: Vec::new(),
+        }
+    }
+
+    /// Adds an item to the list, with an `id`/`label`/`hint`.
+    pub fn item(mut self, value: T, label: impl Display, hint: impl Display) -> Self {
+        self.items.push(SelectItem {
+            value,
+            label: label.to_string(),
+            hint: hint.to_string(),
+        });
+        self.selected.push(false);
+        self
+    }
+
+    /// Runs the prompt and returns the selected items' values.
+    pub fn interact(&mut self) -> io::Result<Vec<T>> {
+        <Self as PromptInteraction<Vec<T>>>::interact(self)
+    }
+
+    /// Runs the prompt on an arbitrary terminal, e.g. an application
+    /// multiplexing several real terminals.
+    pub fn interact_on(&mut self, term: &Term) -> io::Result<Vec<T>> {
+        <Self as PromptInteraction<Vec<T>>>::interact_on(self, term)
+    }
+
+    /// Runs the prompt against a fixed sequence of keys, rendering to
+    /// `term`. Useful for deterministic tests.
+    pub fn interact_with_keys<I>(&mut self, keys: I, term: &Term) -> io::Result<Vec<T>>
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        <Self as PromptInteraction<Vec<T>>>::interact_with_keys(self, keys, term)
+    }
+}
+
+impl<T: Clone + Eq> PromptInteraction<Vec<T>> for MultiSelect<T> {
+    fn notify(&mut self, event: &Event) -> State<Vec<T>> {
+        match event {
+            Event::Key(key) => match key {
+                Key::ArrowUp if self.cursor > 0 => self.cursor -= 1,
+                Key::ArrowDown if self.cursor + 1 < self.items.len() => self.cursor += 1,
+                Key::Char(' ') => {
+                    if let Some(selected) = self.selected.get_mut(self.cursor) {
+                        *selected = !*selected;
+                    }
+                }
+                Key::Enter => {
+                    let values = self
+                        .items
+                        .iter()
+                        .zip(&self.selected)
+                        .filter(|(_, selected)| **selected)
+                        .map(|(item, _)| item.value.clone())
+                        .collect();
+                    return State::Submit(values);
+                }
+                _ => {}
+            },
+        }
+
+        State::Active
+    }
+
+    fn render(&mut self, state: &State<Vec<T>>) -> String {
+        let submitted = matches!(state, State::Submit(_));
+        let labels: Vec<(&str, &str, bool)> = self
+            .items
+            .iter()
+            .zip(&self.selected)
+            .map(|(item, selected)| (item.label.as_str(), item.hint.as_str(), *selected))
+            .collect();
+        ClackTheme.render_multiselect(submitted, &self.prompt, &labels, self.cursor)
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt::Display;
+use std::io;
+
+use console::{Key, Term};
+use zeroize::Zeroizing;
+
+use crate::cursor::StringCursor;
+use crate::interaction::{Event, PromptInteraction, State};
+use crate::theme::ClackTheme;
+use crate::validate::Validate;
+
+type ValidatorFn = Box<dyn Fn(&str) -> Result<(), String>>;
+
+enum Phase {
+    Entry,
+    Confirm,
+}
+
+/// A single-line prompt that masks the typed characters.
+///
+/// See [`crate::password`] for a usage example.
+pub struct Password {
+    prompt: String,
+    mask: char,
+    input: StringCursor,
+    validate: Option<ValidatorFn>,
+    confirm_prompt: Option<String>,
+    mismatch_message: String,
+    phase: Phase,
+    entered_value: Option<Zeroizing<String>>,
+}
+
+impl Password {
+    /// Creates a new password prompt with the given message.
+    pub fn new<S: Display>(prompt: S) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            mask: '▪',
+            input: StringCursor::default(),
+            validate: None,
+            confirm_prompt: None,
+            mismatch_message: "Values do not match.".to_string(),
+            phase: Phase::Entry,
+            entered_value: None,
+        }
+    }
+
+    /// Sets the character used to mask typed input.
+    pub fn mask(mut self, mask: char) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Sets a validator run against the submitted value.
+    pub fn validate<V>(mut self, validator: V) -> Self
+    where
+        V: Validate<str> + 'static,
+    {
+        self.validate = Some(Box::new(move |value: &str| {
+            validator.validate(value).map_err(|err| err.to_string())
+        }));
+        self
+    }
+
+    /// Requires the user to re-enter the value for confirmation, only
+    /// submitting once the two masked entries match.
+    ///
+    /// `confirm_prompt` is shown for the second entry; `mismatch_message`
+    /// is surfaced as a validation error if the two don't match.
+    pub fn with_confirmation(mut self, confirm_prompt: impl Display, mismatch_message: impl Display) -> Self {
+        self.confirm_prompt = Some(confirm_prompt.to_string());
+        self.mismatch_message = mismatch_message.to_string();
+        self
+    }
+
+    /// Runs the prompt and returns the submitted (unmasked) value.
+    pub fn interact(&mut self) -> io::Result<String> {
+        <Self as PromptInteraction<String>>::interact(self)
+    }
+
+    /// Runs the prompt on an arbitrary terminal, e.g. an application
+    /// multiplexing several real terminals.
+    pub fn interact_on(&mut self, term: &Term) -> io::Result<String> {
+        <Self as PromptInteraction<String>>::interact_on(self, term)
+    }
+
+    /// Runs the prompt against a fixed sequence of keys, rendering to
+    /// `term`. Useful for deterministic tests.
+    pub fn interact_with_keys<I>(&mut self, keys: I, term: &Term) -> io::Result<String>
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        <Self as PromptInteraction<String>>::interact_with_keys(self, keys, term)
+    }
+
+    fn current_prompt(&self) -> &str {
+        match self.phase {
+            Phase::Entry => &self.prompt,
+            Phase::Confirm => self
+                .confirm_prompt
+                .as_deref()
+                .unwrap_or(&self.prompt),
+        }
+    }
+}
+
+impl PromptInteraction<String> for Password {
+    fn notify(&mut self, event: &Event) -> State<String> {
+        match event {
+            Event::Key(key) => match key {
+                Key::Char(chr) if !chr.is_ascii_control() => {
+                    self.input.insert(*chr);
+                }
+                Key::Backspace => {
+                    self.input.delete_left();
+                }
+                Key::Del => {
+                    self.input.delete_right();
+                }
+                Key::ArrowLeft => {
+                    self.input.move_left();
+                }
+                Key::ArrowRight => {
+                    self.input.move_right();
+                }
+                Key::Enter => match self.phase {
+                    Phase::Entry => {
+                        if let Some(validator) = &self.validate {
+                            if let Err(err) = validator(&self.input.to_string()) {
+                                return State::Error(err);
+                            }
+                        }
+
+                        let value = Zeroizing::new(self.input.to_string());
+                        self.input.clear();
+
+                        if self.confirm_prompt.is_some() {
+                            self.entered_value = Some(value);
+                            self.phase = Phase::Confirm;
+                        } else {
+                            return State::Submit(value.to_string());
+                        }
+                    }
+                    Phase::Confirm => {
+                        let confirmed = Zeroizing::new(self.input.to_string());
+                        self.input.clear();
+
+                        match &self.entered_value {
+                            Some(value) if *value == confirmed => {
+                                let value = value.to_string();
+                                self.entered_value = None;
+                                return State::Submit(value);
+                            }
+                            _ => {
+                                // Keep `entered_value` and stay in the
+                                // confirmation phase: restart just the
+                                // confirmation entry, not the whole flow.
+                                return State::Error(self.mismatch_message.clone());
+                            }
+                        }
+                    }
+                },
+                _ => {}
+            },
+        }
+
+        State::Active
+    }
+
+    fn render(&mut self, state: &State<String>) -> String {
+        ClackTheme.render_password(state, self.current_prompt(), &self.input, self.mask)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_str(password: &mut Password, value: &str) {
+        for chr in value.chars() {
+            password.notify(&Event::Key(Key::Char(chr)));
+        }
+    }
+
+    #[test]
+    fn matching_entries_submit() {
+        let mut password = Password::new("New password").with_confirmation("Confirm password", "Values do not match.");
+
+        type_str(&mut password, "hunter2");
+        assert!(matches!(password.notify(&Event::Key(Key::Enter)), State::Active));
+        assert!(matches!(password.phase, Phase::Confirm));
+
+        type_str(&mut password, "hunter2");
+        match password.notify(&Event::Key(Key::Enter)) {
+            State::Submit(value) => assert_eq!(value, "hunter2"),
+            _ => panic!("expected Submit, got a different state"),
+        }
+    }
+
+    #[test]
+    fn mismatched_entries_error_and_stay_in_confirm_phase() {
+        let mut password = Password::new("New password").with_confirmation("Confirm password", "Values do not match.");
+
+        type_str(&mut password, "hunter2");
+        password.notify(&Event::Key(Key::Enter));
+
+        type_str(&mut password, "wrong");
+        match password.notify(&Event::Key(Key::Enter)) {
+            State::Error(message) => assert_eq!(message, "Values do not match."),
+            _ => panic!("expected Error on mismatch"),
+        }
+
+        // Stays in the confirmation phase instead of re-asking the first
+        // prompt, and the first entry is preserved for the retry.
+        assert!(matches!(password.phase, Phase::Confirm));
+        assert_eq!(password.current_prompt(), "Confirm password");
+        assert_eq!(password.entered_value.as_deref().map(String::as_str), Some("hunter2"));
+    }
+
+    #[test]
+    fn retries_after_a_second_mismatch() {
+        let mut password = Password::new("New password").with_confirmation("Confirm password", "Values do not match.");
+
+        type_str(&mut password, "hunter2");
+        password.notify(&Event::Key(Key::Enter));
+
+        type_str(&mut password, "wrong-once");
+        assert!(matches!(password.notify(&Event::Key(Key::Enter)), State::Error(_)));
+
+        type_str(&mut password, "wrong-twice");
+        assert!(matches!(password.notify(&Event::Key(Key::Enter)), State::Error(_)));
+        assert!(matches!(password.phase, Phase::Confirm));
+
+        type_str(&mut password, "hunter2");
+        match password.notify(&Event::Key(Key::Enter)) {
+            State::Submit(value) => assert_eq!(value, "hunter2"),
+            _ => panic!("expected Submit after a matching retry"),
+        }
+    }
+}
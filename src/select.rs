@@ -0,0 +1,127 @@
+use std::fmt::Display;
+use std::io;
+
+use console::{Key, Term};
+
+use crate::fuzzy_select::FuzzySelect;
+use crate::interaction::{Event, PromptInteraction, State};
+use crate::theme::ClackTheme;
+
+pub(crate) struct SelectItem<T> {
+    pub(crate) value: T,
+    pub(crate) label: String,
+    pub(crate) hint: String,
+}
+
+/// A single-choice prompt over a list of items.
+///
+/// See [`crate::select`] for a usage example.
+pub struct Select<T> {
+    prompt: String,
+    items: Vec<SelectItem<T>>,
+    cursor: usize,
+}
+
+impl<T: Clone + Eq> Select<T> {
+    /// Creates a new select prompt with the given message.
+    pub fn new(prompt: impl Display) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            items: Vec::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Adds an item to the list, with an `id`/`label`/`hint`.
+    pub fn item(mut self, value: T, label: impl Display, hint: impl Display) -> Self {
+        self.items.push(SelectItem {
+            value,
+            label: label.to_string(),
+            hint: hint.to_string(),
+        });
+        self
+    }
+
+    /// Sets which item is initially highlighted.
+    pub fn initial_value(mut self, value: T) -> Self {
+        if let Some(pos) = self.items.iter().position(|item| item.value == value) {
+            self.cursor = pos;
+        }
+        self
+    }
+
+    /// Runs the prompt and returns the selected item's value.
+    pub fn interact(&mut self) -> io::Result<T> {
+        <Self as PromptInteraction<T>>::interact(self)
+    }
+
+    /// Runs the prompt on an arbitrary terminal, e.g. an application
+    /// multiplexing several real terminals.
+    pub fn interact_on(&mut self, term: &Term) -> io::Result<T> {
+        <Self as PromptInteraction<T>>::interact_on(self, term)
+    }
+
+    /// Runs the prompt against a fixed sequence of keys, rendering to
+    /// `term`. Useful for deterministic tests.
+    pub fn interact_with_keys<I>(&mut self, keys: I, term: &Term) -> io::Result<T>
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        <Self as PromptInteraction<T>>::interact_with_keys(self, keys, term)
+    }
+
+    /// Converts this prompt into a [`FuzzySelect`], letting the user type
+    /// to narrow a long item list instead of scrolling through all of it.
+    pub fn fuzzy(self) -> FuzzySelect<T> {
+        FuzzySelect::from_items(self.prompt, self.items, self.cursor)
+    }
+}
+
+impl<T: Clone + Eq> PromptInteraction<T> for Select<T> {
+    fn notify(&mut self, event: &Event) -> State<T> {
+        match event {
+            Event::Key(key) => match key {
+                Key::ArrowUp if self.cursor > 0 => self.cursor -= 1,
+                Key::ArrowDown if self.cursor + 1 < self.items.len() => self.cursor += 1,
+                Key::Enter => {
+                    if let Some(item) = self.items.get(self.cursor) {
+                        return State::Submit(item.value.clone());
+                    }
+                }
+                _ => {}
+            },
+        }
+
+        State::Active
+    }
+
+    fn render(&mut self, state: &State<T>) -> String {
+        let submitted = matches!(state, State::Submit(_));
+        let labels: Vec<(&str, &str)> = self
+            .items
+            .iter()
+            .map(|item| (item.label.as_str(), item.hint.as_str()))
+            .collect();
+        ClackTheme.render_select(submitted, &self.prompt, &labels, self.cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_carries_over_the_initial_cursor() {
+        let mut fuzzy = Select::new("Pick one")
+            .item("a", "Alpha", "")
+            .item("b", "Bravo", "")
+            .item("c", "Charlie", "")
+            .initial_value("b")
+            .fuzzy();
+
+        assert!(matches!(
+            fuzzy.notify(&Event::Key(Key::Enter)),
+            State::Submit("b")
+        ));
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt::Display;
+
+use console::{Style, Term};
+
+const FRAMES: &[&str] = &["◒", "◐", "◓", "◑"];
+
+/// A progress indicator for long-running, non-interactive work.
+///
+/// See [`crate::spinner`] for a usage example.
+#[derive(Default)]
+pub struct Spinner {
+    message: String,
+}
+
+impl Spinner {
+    /// Starts the spinner, printing `message` next to the first frame.
+    pub fn start(&mut self, message: impl Display) {
+        self.message = message.to_string();
+        let _ = Term::stderr().write_str(&format!("{}  {}\n", FRAMES[0], self.message));
+    }
+
+    /// Stops the spinner, replacing it with a submitted checkmark and
+    /// `message`.
+    pub fn stop(&mut self, message: impl Display) {
+        let _ = Term::stderr().clear_last_lines(1);
+        let symbol = Style::new().green().apply_to("✔").to_string();
+        let _ = Term::stderr().write_str(&format!("{symbol}  {message}\n"));
+    }
+}
@@ -1,54 +1,154 @@
 use std::fmt::Display;
 use std::io;
 
-use console::Key;
+use console::{Key, Term};
 
+use crate::completion::Completion;
 use crate::cursor::StringCursor;
+use crate::history::History;
 use crate::interaction::{Event, State};
+use crate::validate::Validate;
 
-use super::{
-    interaction::PromptInteraction,
-    theme::{ClackTheme, Theme},
-};
+use super::{interaction::PromptInteraction, theme::ClackTheme};
 
 type ValidatorFn = Box<dyn Fn(&str) -> Result<(), String>>;
 
-pub struct Text {
+/// A free-form, single-line text prompt. Used directly, or wrapped by
+/// [`crate::Input`] to additionally parse the result into a target type.
+pub struct Text<'a> {
     prompt: String,
     placeholder: StringCursor,
     input: StringCursor,
     validate: Option<ValidatorFn>,
+    completion: Option<Box<dyn Completion>>,
+    history: Option<&'a mut dyn History>,
+    history_pos: usize,
+    draft: Option<String>,
+    default_value: Option<String>,
 }
 
-impl Text {
+impl<'a> Text<'a> {
+    /// Creates a new text prompt with the given message.
     pub fn new<S: Display>(prompt: S) -> Self {
         Self {
             prompt: prompt.to_string(),
             placeholder: StringCursor::default(),
             input: StringCursor::default(),
             validate: None,
+            completion: None,
+            history: None,
+            history_pos: 0,
+            draft: None,
+            default_value: None,
         }
     }
 
+    /// Pre-populates the editable buffer with `value`, cursor at the end,
+    /// so the user edits an existing value rather than starting empty.
+    pub fn initial_value(mut self, value: &str) -> Self {
+        self.input.replace(value);
+        self
+    }
+
+    /// Substitutes `value` for the submitted value when the user submits
+    /// an empty buffer, before validation.
+    pub fn default_value(mut self, value: &str) -> Self {
+        self.default_value = Some(value.to_string());
+        self
+    }
+
+    /// Sets a placeholder shown (dimmed) while the input is empty.
     pub fn placeholder(mut self, placeholder: &str) -> Self {
         self.placeholder.extend(placeholder);
         self
     }
 
-    pub fn validate<F>(mut self, validator: F) -> Self
+    /// Sets a validator run against the submitted value.
+    pub fn validate<V>(mut self, validator: V) -> Self
     where
-        F: Fn(&str) -> Result<(), String> + 'static,
+        V: Validate<str> + 'static,
     {
-        self.validate = Some(Box::new(validator));
+        self.validate = Some(Box::new(move |value: &str| {
+            validator.validate(value).map_err(|err| err.to_string())
+        }));
+        self
+    }
+
+    /// Attaches a [`Completion`] offering tab-completion suggestions as the
+    /// user types.
+    pub fn completion<C: Completion + 'static>(mut self, completion: C) -> Self {
+        self.completion = Some(Box::new(completion));
         self
     }
 
+    /// Attaches a [`History`], letting the user scroll previously submitted
+    /// values with the `Up`/`Down` arrow keys.
+    pub fn history(mut self, history: &'a mut dyn History) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Runs the prompt and returns the submitted value.
     pub fn interact(&mut self) -> io::Result<String> {
         <Self as PromptInteraction<String>>::interact(self)
     }
+
+    /// Runs the prompt on an arbitrary terminal, e.g. an application
+    /// multiplexing several real terminals.
+    pub fn interact_on(&mut self, term: &Term) -> io::Result<String> {
+        <Self as PromptInteraction<String>>::interact_on(self, term)
+    }
+
+    /// Runs the prompt against a fixed sequence of keys, rendering to
+    /// `term`. Useful for deterministic tests.
+    pub fn interact_with_keys<I>(&mut self, keys: I, term: &Term) -> io::Result<String>
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        <Self as PromptInteraction<String>>::interact_with_keys(self, keys, term)
+    }
+
+    fn accept_completion(&mut self) {
+        if let Some(completion) = &self.completion {
+            if let Some(full) = completion.complete(&self.input.to_string()) {
+                self.input.replace(&full);
+            }
+        }
+    }
+
+    fn recall_older(&mut self) {
+        let Some(history) = self.history.as_deref() else {
+            return;
+        };
+
+        if self.history_pos == 0 {
+            self.draft = Some(self.input.to_string());
+        }
+
+        if let Some(entry) = history.read(self.history_pos) {
+            self.input.replace(&entry);
+            self.history_pos += 1;
+        }
+    }
+
+    fn recall_newer(&mut self) {
+        if self.history.is_none() || self.history_pos == 0 {
+            return;
+        }
+
+        self.history_pos -= 1;
+
+        if self.history_pos == 0 {
+            if let Some(draft) = self.draft.take() {
+                self.input.replace(&draft);
+            }
+        } else if let Some(entry) = self.history.as_deref().and_then(|h| h.read(self.history_pos - 1)) {
+            self.input.replace(&entry);
+        }
+    }
 }
 
-impl PromptInteraction<String> for Text {
+impl PromptInteraction<String> for Text<'_> {
     fn notify(&mut self, event: &Event) -> State<String> {
         match event {
             Event::Key(key) => match key {
@@ -65,15 +165,38 @@ impl PromptInteraction<String> for Text {
                     self.input.move_left();
                 }
                 Key::ArrowRight => {
-                    self.input.move_right();
+                    if self.input.is_cursor_at_end() && self.completion.is_some() {
+                        self.accept_completion();
+                    } else {
+                        self.input.move_right();
+                    }
+                }
+                Key::ArrowUp => {
+                    self.recall_older();
+                }
+                Key::ArrowDown => {
+                    self.recall_newer();
+                }
+                Key::Tab => {
+                    self.accept_completion();
                 }
                 Key::Enter => {
+                    let value = if self.input.is_empty() {
+                        self.default_value.clone().unwrap_or_default()
+                    } else {
+                        self.input.to_string()
+                    };
+
                     if let Some(validator) = &self.validate {
-                        if let Err(err) = validator(&self.input.to_string()) {
+                        if let Err(err) = validator(&value) {
                             return State::Error(err);
                         }
                     }
-                    return State::Submit(self.input.to_string());
+
+                    if let Some(history) = self.history.as_deref_mut() {
+                        history.write(&value);
+                    }
+                    return State::Submit(value);
                 }
                 _ => {}
             },
@@ -83,6 +206,88 @@ impl PromptInteraction<String> for Text {
     }
 
     fn render(&mut self, state: &State<String>) -> String {
-        ClackTheme.render_text(state, &self.prompt, &self.input, &self.placeholder)
+        let current = self.input.to_string();
+        let ghost = self
+            .completion
+            .as_ref()
+            .and_then(|completion| completion.complete(&current))
+            .and_then(|full| full.strip_prefix(&current).map(str::to_string));
+
+        ClackTheme.render_text(
+            state,
+            &self.prompt,
+            &self.input,
+            &self.placeholder,
+            ghost.as_deref(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct VecHistory(Vec<String>);
+
+    impl History for VecHistory {
+        fn read(&self, pos: usize) -> Option<String> {
+            self.0.iter().rev().nth(pos).cloned()
+        }
+
+        fn write(&mut self, val: &str) {
+            self.0.push(val.to_string());
+        }
+    }
+
+    #[test]
+    fn recalls_history_and_restores_the_draft_past_the_newest_entry() {
+        let mut history = VecHistory(vec!["first".to_string(), "second".to_string()]);
+        let mut text = Text::new("prompt").history(&mut history);
+
+        for chr in "hello".chars() {
+            text.notify(&Event::Key(Key::Char(chr)));
+        }
+
+        text.notify(&Event::Key(Key::ArrowUp));
+        assert_eq!(text.input.to_string(), "second");
+
+        text.notify(&Event::Key(Key::ArrowUp));
+        assert_eq!(text.input.to_string(), "first");
+
+        text.notify(&Event::Key(Key::ArrowDown));
+        assert_eq!(text.input.to_string(), "second");
+
+        // Stepping back past the newest entry restores the in-progress
+        // draft rather than leaving the last recalled entry in place.
+        text.notify(&Event::Key(Key::ArrowDown));
+        assert_eq!(text.input.to_string(), "hello");
+    }
+
+    #[test]
+    fn tab_accepts_a_matching_completion() {
+        let mut text = Text::new("prompt").completion(|input: &str| {
+            if "help".starts_with(input) {
+                Some("help".to_string())
+            } else {
+                None
+            }
+        });
+
+        for chr in "he".chars() {
+            text.notify(&Event::Key(Key::Char(chr)));
+        }
+
+        text.notify(&Event::Key(Key::Tab));
+        assert_eq!(text.input.to_string(), "help");
+    }
+
+    #[test]
+    fn enter_on_an_empty_buffer_submits_the_default_value() {
+        let mut text = Text::new("prompt").default_value("fallback");
+
+        match text.notify(&Event::Key(Key::Enter)) {
+            State::Submit(value) => assert_eq!(value, "fallback"),
+            _ => panic!("expected Submit, got a different state"),
+        }
     }
 }
\ No newline at end of file
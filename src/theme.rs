@@ -0,0 +1,325 @@
+use std::sync::{LazyLock, Mutex};
+
+use console::Style;
+
+use crate::alert::AlertKind;
+use crate::cursor::StringCursor;
+use crate::interaction::State;
+
+/// The current lifecycle state of a prompt, used to pick styling.
+pub enum ThemeState {
+    /// The prompt is awaiting input.
+    Active,
+    /// The prompt rejected the current input.
+    Error(String),
+    /// The prompt was submitted.
+    Submit,
+    /// The prompt was cancelled.
+    Cancel,
+}
+
+/// Customizes the look of non-interactive messages (`intro`/`outro`/`log`).
+///
+/// Implement this and pass an instance to [`set_theme`] to override the
+/// default `ClackTheme` styling.
+pub trait Theme: Send + Sync {
+    /// The color used for the state symbol in front of a prompt line.
+    fn state_symbol_color(&self, state: &ThemeState) -> Style {
+        match state {
+            ThemeState::Active => Style::new().cyan(),
+            ThemeState::Error(_) => Style::new().yellow(),
+            ThemeState::Submit => Style::new().green(),
+            ThemeState::Cancel => Style::new().red(),
+        }
+    }
+
+    /// The symbol printed for a plain remark.
+    fn remark_symbol(&self) -> String {
+        "│".to_string()
+    }
+
+    /// The symbol printed for an info message.
+    fn info_symbol(&self) -> String {
+        self.state_symbol_color(&ThemeState::Active)
+            .apply_to("●")
+            .to_string()
+    }
+
+    /// The symbol printed for a warning message.
+    fn warning_symbol(&self) -> String {
+        Style::new().yellow().apply_to("▲").to_string()
+    }
+
+    /// The symbol printed for an error message.
+    fn error_symbol(&self) -> String {
+        Style::new().red().apply_to("■").to_string()
+    }
+
+    /// The symbol printed for an active/success step.
+    fn active_symbol(&self) -> String {
+        self.state_symbol_color(&ThemeState::Submit)
+            .apply_to("◆")
+            .to_string()
+    }
+
+    /// The symbol printed once a step has been submitted.
+    fn submit_symbol(&self) -> String {
+        self.state_symbol_color(&ThemeState::Submit)
+            .apply_to("✔")
+            .to_string()
+    }
+
+    /// Formats the header printed by [`crate::intro`].
+    fn format_intro(&self, title: &str) -> String {
+        format!("┌  {title}\n")
+    }
+
+    /// Formats the footer printed by [`crate::outro`].
+    fn format_outro(&self, message: &str) -> String {
+        format!("└  {message}\n")
+    }
+
+    /// Formats the footer printed by [`crate::outro_cancel`].
+    fn format_outro_cancel(&self, message: &str) -> String {
+        format!("└  {}\n", Style::new().red().apply_to(message))
+    }
+
+    /// Formats a note printed by [`crate::note`]/[`crate::outro_note`].
+    fn format_note(&self, is_outro: bool, prompt: &str, message: &str) -> String {
+        let corner = if is_outro { "└" } else { "│" };
+        format!("{corner}  {prompt}\n{message}\n")
+    }
+
+    /// Formats a single [`crate::log`] line with its leading `symbol`.
+    fn format_log(&self, text: &str, symbol: &str) -> String {
+        format!("{symbol}  {text}\n")
+    }
+}
+
+/// The default, opinionated theme used unless overridden with [`set_theme`].
+pub struct ClackTheme;
+
+impl Theme for ClackTheme {}
+
+impl ClackTheme {
+    /// Renders a free-form [`crate::Text`] prompt.
+    ///
+    /// `completion` is the remaining (not-yet-typed) part of a pending tab
+    /// completion, rendered as dimmed "ghost" text after the current input.
+    pub fn render_text(
+        &self,
+        state: &State<String>,
+        prompt: &str,
+        input: &StringCursor,
+        placeholder: &StringCursor,
+        completion: Option<&str>,
+    ) -> String {
+        let symbol = match state {
+            State::Error(_) => self.error_symbol(),
+            State::Submit(_) => self.submit_symbol(),
+            _ => self.active_symbol(),
+        };
+
+        let value = if input.is_empty() {
+            Style::new().dim().apply_to(placeholder).to_string()
+        } else {
+            match completion {
+                Some(ghost) if !ghost.is_empty() => {
+                    format!("{input}{}", Style::new().dim().apply_to(ghost))
+                }
+                _ => input.to_string(),
+            }
+        };
+
+        let mut out = format!("{symbol}  {prompt}\n│  {value}\n");
+
+        if let State::Error(err) = state {
+            out.push_str(&format!("└  {}\n", Style::new().yellow().apply_to(err)));
+        }
+
+        out
+    }
+
+    /// Renders an [`crate::Alert`] prompt, styling the message according
+    /// to its [`AlertKind`].
+    pub fn render_alert(&self, state: &State<()>, prompt: &str, kind: AlertKind) -> String {
+        let symbol = match (state, kind) {
+            (State::Submit(_), _) => self.submit_symbol(),
+            (_, AlertKind::Warning) => self.warning_symbol(),
+            (_, AlertKind::Error) => self.error_symbol(),
+            (_, AlertKind::Info) => self.info_symbol(),
+        };
+
+        match state {
+            State::Submit(_) => format!("{symbol}  {prompt}\n"),
+            _ => format!("{symbol}  {prompt}\n│  Press Enter to continue…\n"),
+        }
+    }
+
+    /// Renders a [`crate::Confirm`] prompt.
+    pub fn render_confirm(
+        &self,
+        state: &State<bool>,
+        prompt: &str,
+        active: &str,
+        inactive: &str,
+        selected: bool,
+    ) -> String {
+        let symbol = match state {
+            State::Submit(_) => self.submit_symbol(),
+            _ => self.active_symbol(),
+        };
+
+        let (active, inactive) = if selected {
+            (Style::new().underlined().apply_to(active).to_string(), inactive.to_string())
+        } else {
+            (active.to_string(), Style::new().underlined().apply_to(inactive).to_string())
+        };
+
+        format!("{symbol}  {prompt}\n│  {active} / {inactive}\n")
+    }
+
+    /// Renders a [`crate::Password`] prompt, masking the typed input.
+    pub fn render_password(
+        &self,
+        state: &State<String>,
+        prompt: &str,
+        input: &StringCursor,
+        mask: char,
+    ) -> String {
+        let symbol = match state {
+            State::Error(_) => self.error_symbol(),
+            State::Submit(_) => self.submit_symbol(),
+            _ => self.active_symbol(),
+        };
+
+        let masked: String = input.to_string().chars().map(|_| mask).collect();
+        let mut out = format!("{symbol}  {prompt}\n│  {masked}\n");
+
+        if let State::Error(err) = state {
+            out.push_str(&format!("└  {}\n", Style::new().yellow().apply_to(err)));
+        }
+
+        out
+    }
+
+    /// Renders a [`crate::Select`] prompt.
+    pub fn render_select(
+        &self,
+        submitted: bool,
+        prompt: &str,
+        items: &[(&str, &str)],
+        cursor: usize,
+    ) -> String {
+        let symbol = if submitted {
+            self.submit_symbol()
+        } else {
+            self.active_symbol()
+        };
+
+        let mut out = format!("{symbol}  {prompt}\n");
+        for (index, (label, hint)) in items.iter().enumerate() {
+            let line = if hint.is_empty() {
+                label.to_string()
+            } else {
+                format!("{label} ({hint})")
+            };
+            let line = if index == cursor {
+                Style::new().cyan().apply_to(format!("● {line}")).to_string()
+            } else {
+                format!("○ {line}")
+            };
+            out.push_str(&format!("│  {line}\n"));
+        }
+        out
+    }
+
+    /// Renders a [`crate::FuzzySelect`] prompt, emphasizing the matched
+    /// characters (given as `chars()` ordinal positions per item, not byte
+    /// offsets) within each label.
+    pub fn render_fuzzy_select(
+        &self,
+        submitted: bool,
+        prompt: &str,
+        query: &StringCursor,
+        items: &[(&str, &str, &[usize])],
+        cursor: usize,
+    ) -> String {
+        let symbol = if submitted {
+            self.submit_symbol()
+        } else {
+            self.active_symbol()
+        };
+
+        let mut out = format!("{symbol}  {prompt}\n│  {query}\n");
+        for (index, (label, hint, positions)) in items.iter().enumerate() {
+            let mut rendered = String::new();
+            for (i, chr) in label.chars().enumerate() {
+                if positions.contains(&i) {
+                    rendered.push_str(&Style::new().cyan().bold().apply_to(chr).to_string());
+                } else {
+                    rendered.push(chr);
+                }
+            }
+            if !hint.is_empty() {
+                rendered.push_str(&format!(" ({hint})"));
+            }
+
+            let line = if index == cursor {
+                format!("● {rendered}")
+            } else {
+                format!("○ {rendered}")
+            };
+            out.push_str(&format!("│  {line}\n"));
+        }
+        out
+    }
+
+    /// Renders a [`crate::MultiSelect`] prompt.
+    pub fn render_multiselect(
+        &self,
+        submitted: bool,
+        prompt: &str,
+        items: &[(&str, &str, bool)],
+        cursor: usize,
+    ) -> String {
+        let symbol = if submitted {
+            self.submit_symbol()
+        } else {
+            self.active_symbol()
+        };
+
+        let mut out = format!("{symbol}  {prompt}\n");
+        for (index, (label, hint, selected)) in items.iter().enumerate() {
+            let checkbox = if *selected { "■" } else { "□" };
+            let line = if hint.is_empty() {
+                label.to_string()
+            } else {
+                format!("{label} ({hint})")
+            };
+            let line = format!("{checkbox} {line}");
+            let line = if index == cursor {
+                Style::new().cyan().apply_to(line).to_string()
+            } else {
+                line
+            };
+            out.push_str(&format!("│  {line}\n"));
+        }
+        out
+    }
+}
+
+/// The globally active theme, used by [`crate::intro`], [`crate::outro`]
+/// and the [`crate::log`] functions.
+pub static THEME: LazyLock<Mutex<Box<dyn Theme>>> = LazyLock::new(|| Mutex::new(Box::new(ClackTheme)));
+
+/// Overrides the global theme. See the [`Theme`] trait for customizable
+/// hooks.
+pub fn set_theme<T: Theme + 'static>(theme: T) {
+    *THEME.lock().unwrap() = Box::new(theme);
+}
+
+/// Restores the default `ClackTheme`.
+pub fn reset_theme() {
+    *THEME.lock().unwrap() = Box::new(ClackTheme);
+}
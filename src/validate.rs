@@ -0,0 +1,20 @@
+/// Validates a submitted prompt value, returning an error message to
+/// display when the value is rejected.
+///
+/// Implemented for any `Fn(&T) -> Result<(), E>` closure so prompts can
+/// accept either a function pointer/closure or a custom validator type.
+pub trait Validate<T: ?Sized> {
+    /// The error type returned on failed validation.
+    type Err: ToString;
+
+    /// Validates `value`, returning `Err` with a message if it is rejected.
+    fn validate(&self, value: &T) -> Result<(), Self::Err>;
+}
+
+impl<T: ?Sized, E: ToString, F: Fn(&T) -> Result<(), E>> Validate<T> for F {
+    type Err = E;
+
+    fn validate(&self, value: &T) -> Result<(), Self::Err> {
+        self(value)
+    }
+}